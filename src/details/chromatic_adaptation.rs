@@ -0,0 +1,131 @@
+//! Bradford chromatic adaptation between reference white points.
+//!
+//! [`LinearConvertFromRaw::linear_part_raw`][crate::details::traits::LinearConvertFromRaw::linear_part_raw]
+//! is generated from a source and destination [`LinearColorSpace`][crate::details::traits::LinearColorSpace],
+//! and previously only composed the two spaces' primaries matrices. That is only correct when both
+//! spaces share a [`WhitePoint`] — converting, say, D65 sRGB into a D50 working space would silently
+//! shift color. This module provides the missing piece: a Bradford-adapted transform between two
+//! reference whites, meant to be folded into that generated matrix.
+
+use glam::{Mat3, Vec3};
+use kolor::details::color::WhitePoint;
+
+use super::traits::{LinearColorSpace, LinearConvertFromRaw};
+
+/// The Bradford cone-response matrix, used to transform CIE XYZ tristimulus values into the
+/// cone-response domain in which chromatic adaptation is performed.
+#[rustfmt::skip]
+const BRADFORD: Mat3 = Mat3::from_cols(
+    // column-major: each Vec3 here is a *column* of the matrix
+    Vec3::new(0.8951, -0.7502, 0.0389),
+    Vec3::new(0.2664, 1.7135, -0.0685),
+    Vec3::new(-0.1614, 0.0367, 1.0296),
+);
+
+/// The inverse of [`BRADFORD`], precomputed rather than derived via `Mat3::inverse` at runtime —
+/// it's a fixed constant, not something that depends on any call's inputs.
+#[rustfmt::skip]
+const BRADFORD_INV: Mat3 = Mat3::from_cols(
+    // column-major: each Vec3 here is a *column* of the matrix
+    Vec3::new(0.9869929, 0.4323053, -0.0085287),
+    Vec3::new(-0.1470543, 0.5183603, 0.0400428),
+    Vec3::new(0.1599627, 0.0492912, 0.9684867),
+);
+
+/// The CIE XYZ tristimulus values of a standard illuminant's reference white, normalized so `Y = 1`.
+fn white_point_xyz(white_point: WhitePoint) -> Vec3 {
+    // Values from the CIE standard illuminant tables (2 degree observer).
+    match white_point {
+        WhitePoint::A => Vec3::new(1.09850, 1.0, 0.35585),
+        WhitePoint::B => Vec3::new(0.99072, 1.0, 0.85223),
+        WhitePoint::C => Vec3::new(0.98074, 1.0, 1.18232),
+        WhitePoint::D50 => Vec3::new(0.96422, 1.0, 0.82521),
+        WhitePoint::D55 => Vec3::new(0.95682, 1.0, 0.92149),
+        WhitePoint::D65 => Vec3::new(0.95047, 1.0, 1.08883),
+        WhitePoint::D75 => Vec3::new(0.94972, 1.0, 1.22638),
+        WhitePoint::E => Vec3::new(1.0, 1.0, 1.0),
+    }
+}
+
+/// Computes the Bradford chromatic-adaptation matrix that transforms CIE XYZ tristimulus values
+/// under `src` illumination into CIE XYZ tristimulus values under `dst` illumination.
+///
+/// When `src == dst` this returns the identity matrix, so composing it into an existing transform
+/// is a no-op for same-white-point conversions.
+pub fn bradford_adaptation_matrix(src: WhitePoint, dst: WhitePoint) -> Mat3 {
+    let src_cone = BRADFORD * white_point_xyz(src);
+    let dst_cone = BRADFORD * white_point_xyz(dst);
+
+    let scale = Mat3::from_diagonal(Vec3::new(
+        dst_cone.x / src_cone.x,
+        dst_cone.y / src_cone.y,
+        dst_cone.z / src_cone.z,
+    ));
+
+    BRADFORD_INV * scale * BRADFORD
+}
+
+/// Composes a source RGB-to-XYZ matrix, a Bradford chromatic-adaptation step between the two
+/// white points, and a destination XYZ-to-RGB matrix into the single 3x3 matrix that should be
+/// applied in `linear_part_raw`.
+pub fn adapted_linear_transform(
+    src_rgb_to_xyz: Mat3,
+    src_white: WhitePoint,
+    dst_xyz_to_rgb: Mat3,
+    dst_white: WhitePoint,
+) -> Mat3 {
+    dst_xyz_to_rgb * bradford_adaptation_matrix(src_white, dst_white) * src_rgb_to_xyz
+}
+
+/// The implementation of [`LinearConvertFromRaw::linear_part_raw`] for every pair of
+/// [`LinearColorSpace`]s: compose `Src`'s RGB-to-XYZ matrix, a Bradford chromatic-adaptation step
+/// between the two spaces' white points, and `Dst`'s XYZ-to-RGB matrix into a single 3x3
+/// transform. When both spaces share a white point, the adaptation step is the identity and this
+/// reduces to composing the two primaries matrices.
+///
+/// The composed matrix (including `Dst::RGB_TO_XYZ`'s inversion) is rebuilt on every call; it is
+/// not cached.
+impl<Src, Dst> LinearConvertFromRaw<Src> for Dst
+where
+    Src: LinearColorSpace,
+    Dst: LinearColorSpace,
+{
+    fn linear_part_raw(raw: &mut Vec3) {
+        let transform = adapted_linear_transform(
+            Src::RGB_TO_XYZ,
+            Src::WHITE_POINT,
+            Dst::RGB_TO_XYZ.inverse(),
+            Dst::WHITE_POINT,
+        );
+        *raw = transform * *raw;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bradford_inv_is_the_actual_inverse_of_bradford() {
+        let identity_diff = (BRADFORD * BRADFORD_INV - Mat3::IDENTITY).to_cols_array();
+        assert!(identity_diff.iter().all(|c| c.abs() < 1e-5));
+    }
+
+    #[test]
+    fn same_white_point_adaptation_is_identity() {
+        let m = bradford_adaptation_matrix(WhitePoint::D65, WhitePoint::D65);
+        let identity_diff = (m - Mat3::IDENTITY).to_cols_array();
+        assert!(identity_diff.iter().all(|c| c.abs() < 1e-6));
+    }
+
+    #[test]
+    fn linear_part_raw_is_identity_within_the_same_space() {
+        use crate::encodings::oklab::LinearSrgb;
+
+        let original = Vec3::new(0.3, 0.6, 0.9);
+        let mut raw = original;
+        LinearSrgb::linear_part_raw(&mut raw);
+
+        assert!((raw - original).abs().max_element() < 1e-5);
+    }
+}
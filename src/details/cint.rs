@@ -0,0 +1,124 @@
+//! Interchange with the [`cint`](https://docs.rs/cint) crate, gated behind the `cint` feature.
+//!
+//! `cint` defines a set of component-typed "interchange" structs (`EncodedSrgb`, `LinearSrgb`,
+//! `Oklab`, `Xyz`, and their alpha variants) meant as a stable boundary between color crates that
+//! otherwise know nothing about each other. This module provides the glue to move between a
+//! [`ColorEncoding`] and its matching `cint` struct. Only `Oklab` (as `cint::Oklab<f32>`) and
+//! `EncodedSrgbU8` (as `cint::EncodedSrgb<u8>`) are associated here; the other encodings have no
+//! [`CintEncoding`] impl.
+#![cfg(feature = "cint")]
+
+use crate::Color;
+
+use super::traits::ColorEncoding;
+
+/// Associates a [`ColorEncoding`] with the [`cint`] interchange struct that has the same
+/// memory layout as `Self::Repr`.
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Self::CintRepr` has the exact same layout (size, alignment,
+/// field order) as `Self::Repr`, so that the conversions provided by this trait are sound to
+/// perform as a bitwise copy rather than a field-by-field one.
+pub unsafe trait CintEncoding: ColorEncoding {
+    /// The `cint` interchange struct matching this encoding, e.g. `cint::EncodedSrgb<f32>`.
+    type CintRepr: Sized + Clone + Copy + 'static;
+}
+
+impl<E> Color<E>
+where
+    E: CintEncoding,
+{
+    /// Converts this color into its corresponding `cint` interchange struct.
+    ///
+    /// Because [`CintEncoding`] guarantees the two types share a layout, this is a plain
+    /// reinterpretation of the bits and performs no math. This has to go through
+    /// `transmute_copy` rather than `transmute`, since `transmute` can't typecheck between two
+    /// unconstrained associated types even when they're the same size; the `const` block below
+    /// is what actually catches a `CintEncoding` impl whose `CintRepr` doesn't match `Repr`'s
+    /// size, at compile time.
+    #[inline]
+    pub fn into_cint(self) -> E::CintRepr {
+        const { assert!(core::mem::size_of::<E::Repr>() == core::mem::size_of::<E::CintRepr>()) };
+        // SAFETY: `CintEncoding` guarantees `E::CintRepr` has the same layout as `E::Repr`, and
+        // the `const` block above has already checked the sizes match.
+        unsafe { core::mem::transmute_copy(&self.repr) }
+    }
+
+    /// Builds a color from its corresponding `cint` interchange struct.
+    ///
+    /// Because [`CintEncoding`] guarantees the two types share a layout, this is a plain
+    /// reinterpretation of the bits and performs no math. See [`into_cint`][Self::into_cint] for
+    /// why this uses `transmute_copy` plus an explicit size assertion instead of `transmute`.
+    #[inline]
+    pub fn from_cint(cint: E::CintRepr) -> Self {
+        const { assert!(core::mem::size_of::<E::Repr>() == core::mem::size_of::<E::CintRepr>()) };
+        // SAFETY: `CintEncoding` guarantees `E::CintRepr` has the same layout as `E::Repr`, and
+        // the `const` block above has already checked the sizes match.
+        let repr = unsafe { core::mem::transmute_copy(&cint) };
+        Color { repr }
+    }
+}
+
+/// Implements [`CintEncoding`] for a [`ColorEncoding`], pointing it at a concrete `cint` struct,
+/// and provides the corresponding `From`/`Into` impls between `Color<$encoding>` and
+/// `$cint_ty`.
+#[macro_export]
+macro_rules! impl_cint_encoding {
+    ($encoding:ty, $cint_ty:ty) => {
+        unsafe impl $crate::details::cint::CintEncoding for $encoding {
+            type CintRepr = $cint_ty;
+        }
+
+        impl From<$crate::Color<$encoding>> for $cint_ty {
+            #[inline]
+            fn from(color: $crate::Color<$encoding>) -> Self {
+                color.into_cint()
+            }
+        }
+
+        impl From<$cint_ty> for $crate::Color<$encoding> {
+            #[inline]
+            fn from(cint: $cint_ty) -> Self {
+                $crate::Color::<$encoding>::from_cint(cint)
+            }
+        }
+    };
+}
+
+impl_cint_encoding!(crate::encodings::oklab::Oklab, cint::Oklab<f32>);
+impl_cint_encoding!(
+    crate::encodings::encoded_srgb_u8::EncodedSrgbU8,
+    cint::EncodedSrgb<u8>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encodings::encoded_srgb_u8::EncodedSrgbU8;
+    use crate::encodings::oklab::Oklab;
+
+    #[test]
+    fn oklab_cint_round_trip_preserves_bits() {
+        let oklab = Color::<Oklab> {
+            repr: glam::Vec3::new(0.6, 0.1, -0.05),
+        };
+
+        let cint: cint::Oklab<f32> = oklab.into_cint();
+        let back = Color::<Oklab>::from_cint(cint);
+
+        assert_eq!(oklab.repr, back.repr);
+    }
+
+    #[test]
+    fn encoded_srgb_u8_cint_round_trip_preserves_bits() {
+        let srgb = Color::<EncodedSrgbU8> {
+            repr: [10, 20, 30],
+        };
+
+        let cint: cint::EncodedSrgb<u8> = srgb.into_cint();
+        let back = Color::<EncodedSrgbU8>::from_cint(cint);
+
+        assert_eq!(srgb.repr, back.repr);
+    }
+}
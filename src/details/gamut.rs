@@ -0,0 +1,212 @@
+//! Gamut mapping strategies for [`ConvertFrom::map_src`][super::traits::ConvertFrom::map_src].
+//!
+//! Converting a wide-gamut encoding (BT.2020, ACEScg) into a narrower one (sRGB) can produce
+//! components outside `[0, 1]` once transformed into the destination's linear space. `map_src` is
+//! the hook meant to deal with that, but it ships as a no-op. [`GamutClip`] and [`GamutCompress`]
+//! are two ready-made strategies, both working by holding a color's Oklab lightness and hue fixed
+//! and adjusting its chroma until it re-enters the destination gamut.
+//!
+//! The Oklab math only makes sense on linear sRGB, so `Src`'s and `Dst`'s own linear spaces are
+//! bridged through [`LinearSrgb`] rather than assumed to already be sRGB.
+
+use glam::Vec3;
+
+use crate::details::traits::{ColorEncoding, LinearConvertFromRaw};
+use crate::encodings::oklab::{
+    lab_to_lch, lch_to_lab, linear_srgb_to_oklab, oklab_to_linear_srgb, LinearSrgb,
+};
+
+/// A strategy that can be attached to a [`ConvertFrom`][super::traits::ConvertFrom] impl's
+/// [`map_src`][super::traits::ConvertFrom::map_src] hook to bring a color's chroma back into
+/// `Dst`'s gamut before the normal source transform runs.
+///
+/// Call this from `map_src` like:
+///
+/// ```ignore
+/// fn map_src(src: &mut SrcEnc::Repr) {
+///     GamutMap::<SrcEnc, Self>::map(&GamutCompress::default(), src);
+/// }
+/// ```
+pub trait GamutMap<Src, Dst>
+where
+    Src: ColorEncoding,
+    Dst: ColorEncoding,
+    LinearSrgb: LinearConvertFromRaw<Src::LinearSpace>,
+    Src::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+    Dst::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    /// Adjusts `src` in place so that it falls within `Dst`'s gamut.
+    fn map(&self, src: &mut Src::Repr);
+}
+
+/// Hard-clips out-of-gamut colors to the destination gamut boundary by reducing Oklab chroma to
+/// the maximum in-gamut value for the color's lightness and hue.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GamutClip;
+
+impl<Src, Dst> GamutMap<Src, Dst> for GamutClip
+where
+    Src: ColorEncoding,
+    Dst: ColorEncoding,
+    LinearSrgb: LinearConvertFromRaw<Src::LinearSpace>,
+    Src::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+    Dst::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    fn map(&self, src: &mut Src::Repr) {
+        let (src_linear, alpha) = Src::src_transform_raw(*src);
+        let srgb_linear = linear_to_srgb::<Src>(src_linear);
+        if is_in_gamut::<Dst>(srgb_linear) {
+            return;
+        }
+
+        let lch = lab_to_lch(linear_srgb_to_oklab(srgb_linear));
+        let c_max = max_in_gamut_chroma::<Dst>(lch.x, lch.z);
+        let clipped_srgb = oklab_to_linear_srgb(lch_to_lab(Vec3::new(lch.x, c_max, lch.z)));
+
+        *src = Src::dst_transform_raw(srgb_to_linear::<Src>(clipped_srgb), alpha);
+    }
+}
+
+/// Softly compresses out-of-gamut colors toward the destination gamut boundary: colors already
+/// inside the gamut below `knee` (in Oklab chroma units) pass through unchanged, while colors
+/// above it are compressed toward the maximum in-gamut chroma with an exponential knee, instead
+/// of being hard-clipped.
+#[derive(Debug, Clone, Copy)]
+pub struct GamutCompress {
+    /// The Oklab chroma below which colors are left untouched.
+    pub knee: f32,
+}
+
+/// The default knee, in Oklab chroma units. Chroma at or below it is left untouched; chroma above
+/// it is compressed, whether or not the color is already inside the gamut.
+pub const DEFAULT_KNEE: f32 = 0.05;
+
+impl Default for GamutCompress {
+    fn default() -> Self {
+        GamutCompress { knee: DEFAULT_KNEE }
+    }
+}
+
+impl<Src, Dst> GamutMap<Src, Dst> for GamutCompress
+where
+    Src: ColorEncoding,
+    Dst: ColorEncoding,
+    LinearSrgb: LinearConvertFromRaw<Src::LinearSpace>,
+    Src::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+    Dst::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    fn map(&self, src: &mut Src::Repr) {
+        let (src_linear, alpha) = Src::src_transform_raw(*src);
+        let srgb_linear = linear_to_srgb::<Src>(src_linear);
+
+        let lch = lab_to_lch(linear_srgb_to_oklab(srgb_linear));
+        let c_max = max_in_gamut_chroma::<Dst>(lch.x, lch.z);
+
+        let c = lch.y;
+        let t = self.knee.min(c_max);
+        if c <= t {
+            return;
+        }
+        let compressed = c_max - (c_max - t) * (-(c - t) / (c_max - t)).exp();
+
+        let result_srgb = oklab_to_linear_srgb(lch_to_lab(Vec3::new(lch.x, compressed, lch.z)));
+        *src = Src::dst_transform_raw(srgb_to_linear::<Src>(result_srgb), alpha);
+    }
+}
+
+/// Converts `linear` (in `E::LinearSpace`) into linear sRGB, the space the Oklab helpers assume.
+fn linear_to_srgb<E>(linear: Vec3) -> Vec3
+where
+    E: ColorEncoding,
+    LinearSrgb: LinearConvertFromRaw<E::LinearSpace>,
+{
+    let mut srgb = linear;
+    LinearSrgb::linear_part_raw(&mut srgb);
+    srgb
+}
+
+/// Converts `srgb` (linear sRGB) into `E::LinearSpace`.
+fn srgb_to_linear<E>(srgb: Vec3) -> Vec3
+where
+    E: ColorEncoding,
+    E::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    let mut linear = srgb;
+    E::LinearSpace::linear_part_raw(&mut linear);
+    linear
+}
+
+/// Whether `srgb_linear` (linear sRGB) falls within `Dst`'s representable gamut, i.e. its
+/// components are all within `[0, 1]` once transformed into `Dst::LinearSpace`.
+fn is_in_gamut<Dst>(srgb_linear: Vec3) -> bool
+where
+    Dst: ColorEncoding,
+    Dst::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    let dst_linear = srgb_to_linear::<Dst>(srgb_linear);
+    dst_linear.cmpge(Vec3::ZERO).all() && dst_linear.cmple(Vec3::ONE).all()
+}
+
+/// Finds the maximum Oklab chroma, at the given lightness and hue, whose corresponding color is
+/// still within `Dst`'s gamut, via binary search.
+fn max_in_gamut_chroma<Dst>(l: f32, hue: f32) -> f32
+where
+    Dst: ColorEncoding,
+    Dst::LinearSpace: LinearConvertFromRaw<LinearSrgb>,
+{
+    let mut lo = 0.0f32;
+    let mut hi = 0.5f32;
+
+    for _ in 0..24 {
+        let mid = (lo + hi) * 0.5;
+        let linear = oklab_to_linear_srgb(lch_to_lab(Vec3::new(l, mid, hue)));
+        if is_in_gamut::<Dst>(linear) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encodings::oklab::Oklab;
+
+    #[test]
+    fn in_gamut_color_is_left_untouched() {
+        let linear = Vec3::new(0.2, 0.4, 0.6);
+        let mut repr = Oklab::dst_transform_raw(linear, 1.0);
+        let original = repr;
+
+        GamutMap::<Oklab, Oklab>::map(&GamutClip, &mut repr);
+
+        assert_eq!(repr, original);
+    }
+
+    #[test]
+    fn out_of_gamut_color_is_clipped_into_gamut() {
+        // A very high-chroma Oklab color, well outside the sRGB gamut at this lightness/hue.
+        let mut repr = lch_to_lab(Vec3::new(0.7, 0.5, 0.0));
+
+        GamutMap::<Oklab, Oklab>::map(&GamutClip, &mut repr);
+
+        let (linear, _) = Oklab::src_transform_raw(repr);
+        assert!(linear.cmpge(Vec3::ZERO).all() && linear.cmple(Vec3::ONE).all());
+    }
+
+    #[test]
+    fn compress_leaves_low_chroma_untouched_but_pulls_in_high_chroma() {
+        let mut low_chroma = lch_to_lab(Vec3::new(0.7, 0.01, 0.0));
+        let original = low_chroma;
+        GamutMap::<Oklab, Oklab>::map(&GamutCompress::default(), &mut low_chroma);
+        assert_eq!(low_chroma, original);
+
+        let mut high_chroma = lch_to_lab(Vec3::new(0.7, 0.5, 0.0));
+        GamutMap::<Oklab, Oklab>::map(&GamutCompress::default(), &mut high_chroma);
+        let (linear, _) = Oklab::src_transform_raw(high_chroma);
+        assert!(linear.cmpge(Vec3::ZERO).all() && linear.cmple(Vec3::ONE).all());
+    }
+}
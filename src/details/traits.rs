@@ -2,7 +2,7 @@ use core::ops::{Add, Mul, Sub};
 
 use crate::Color;
 
-use glam::Vec3;
+use glam::{Mat3, Vec3};
 use kolor::details::color::{RGBPrimaries, WhitePoint};
 
 pub trait ColorEncoding: Sized + 'static {
@@ -56,6 +56,19 @@ pub trait Saturate: ColorEncoding {
     fn saturate(repr: Self::Repr) -> Self::Repr;
 }
 
+/// Implemented by cylindrical color encodings (e.g. [`Hsv`][crate::encodings::hsv::Hsv],
+/// [`Hsl`][crate::encodings::hsl::Hsl]) that have an angular hue component which can be rotated.
+pub trait HueRotate: ColorEncoding {
+    /// Rotates `repr`'s hue by `degrees`, wrapping around the hue circle.
+    fn hue_rotate(repr: Self::Repr, degrees: f32) -> Self::Repr;
+}
+
+/// Implemented by color encodings with a saturation component that can be scaled directly.
+pub trait AdjustSaturation: ColorEncoding {
+    /// Scales `repr`'s saturation by `factor`.
+    fn adjust_saturation(repr: Self::Repr, factor: f32) -> Self::Repr;
+}
+
 /// Implemented by color encodings which can perform linear interpolation between colors.
 /// The interpolation is not necessarily perceptually-linear, it is just linear within the
 /// given encoding.
@@ -93,6 +106,10 @@ pub trait WorkingEncoding: ColorEncoding {}
 pub trait LinearColorSpace {
     const PRIMARIES: RGBPrimaries;
     const WHITE_POINT: WhitePoint;
+
+    /// The 3x3 matrix that converts linear RGB values in this space into CIE XYZ tristimulus
+    /// values, under this space's own [`WHITE_POINT`][Self::WHITE_POINT].
+    const RGB_TO_XYZ: Mat3;
 }
 
 /// A trait that marks `Self` as being a color encoding which is able to be directly converted from `SrcEnc`,
@@ -106,12 +123,21 @@ where
     /// If required or desired, perform a mapping of some kind to the input
     /// before it undergoes its source transform. This may be desirable to perform some form of
     /// gamut mapping if the src encoding has a larger size of representable colors than te dst encoding.
+    ///
+    /// [`GamutClip`][crate::details::gamut::GamutClip] and
+    /// [`GamutCompress`][crate::details::gamut::GamutCompress] are ready-made strategies for this.
     #[inline(always)]
     fn map_src(_src: &mut SrcEnc::Repr) {}
 }
 
 /// Performs the raw conversion from the [`LinearColorSpace`] represented by `SrcSpc` to
 /// the [`LinearColorSpace`] represented by `Self`.
+///
+/// The generated implementation composes the primaries matrices of both spaces with a Bradford
+/// chromatic-adaptation step (see [`chromatic_adaptation`][crate::details::chromatic_adaptation])
+/// between their [`WHITE_POINT`][LinearColorSpace::WHITE_POINT]s, so conversions between spaces
+/// with different reference whites (e.g. D65 sRGB to a D50 working space) map correctly. When
+/// both white points match, the adaptation step is the identity and behavior is unchanged.
 pub trait LinearConvertFromRaw<SrcSpace: LinearColorSpace>: LinearColorSpace {
     fn linear_part_raw(raw: &mut Vec3);
 }
@@ -0,0 +1,229 @@
+//! Low-precision, integer-backed sRGB encodings.
+//!
+//! Most encodings in this crate store their components as `f32` inside a [`glam::Vec3`]. That is
+//! the right choice for doing math on colors, but a poor fit for storing or transmitting them —
+//! most image formats and framebuffers use 8 bits per channel. [`EncodedSrgbU8`] and
+//! [`EncodedSrgbU8Alpha`] store gamma-encoded sRGB directly as bytes, expanding to linear `Vec3`
+//! on the way in and quantizing back to bytes on the way out, so they round-trip through the same
+//! [`ColorEncoding`] machinery as every other encoding. [`EncodedSrgbU16`] does the same at 16
+//! bits per channel for formats that need the extra precision.
+
+use glam::Vec3;
+
+use crate::details::traits::{ColorEncoding, ColorRepr, ComponentStructFor};
+
+impl ColorRepr for [u8; 3] {
+    type Element = u8;
+}
+
+impl ColorRepr for [u8; 4] {
+    type Element = u8;
+}
+
+impl ColorRepr for [u16; 3] {
+    type Element = u16;
+}
+
+/// The `{ r, g, b }` component struct backing [`EncodedSrgbU8`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Srgb8Components {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+unsafe impl ComponentStructFor<[u8; 3]> for Srgb8Components {
+    fn cast(repr: &[u8; 3]) -> &Self {
+        unsafe { &*(repr as *const [u8; 3] as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut [u8; 3]) -> &mut Self {
+        unsafe { &mut *(repr as *mut [u8; 3] as *mut Self) }
+    }
+}
+
+/// The `{ r, g, b, a }` component struct backing [`EncodedSrgbU8Alpha`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Srgb8AlphaComponents {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+unsafe impl ComponentStructFor<[u8; 4]> for Srgb8AlphaComponents {
+    fn cast(repr: &[u8; 4]) -> &Self {
+        unsafe { &*(repr as *const [u8; 4] as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut [u8; 4]) -> &mut Self {
+        unsafe { &mut *(repr as *mut [u8; 4] as *mut Self) }
+    }
+}
+
+/// The `{ r, g, b }` component struct backing [`EncodedSrgbU16`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(C)]
+pub struct Srgb16Components {
+    pub r: u16,
+    pub g: u16,
+    pub b: u16,
+}
+
+unsafe impl ComponentStructFor<[u16; 3]> for Srgb16Components {
+    fn cast(repr: &[u16; 3]) -> &Self {
+        unsafe { &*(repr as *const [u16; 3] as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut [u16; 3]) -> &mut Self {
+        unsafe { &mut *(repr as *mut [u16; 3] as *mut Self) }
+    }
+}
+
+/// Gamma-encoded sRGB, stored as 3 `u8` channels with no alpha.
+pub struct EncodedSrgbU8;
+
+impl ColorEncoding for EncodedSrgbU8 {
+    type Repr = [u8; 3];
+    type ComponentStruct = Srgb8Components;
+    type LinearSpace = super::oklab::LinearSrgb;
+
+    const NAME: &'static str = "EncodedSrgbU8";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (
+            Vec3::new(
+                srgb_eotf(repr[0] as f32 / 255.0),
+                srgb_eotf(repr[1] as f32 / 255.0),
+                srgb_eotf(repr[2] as f32 / 255.0),
+            ),
+            1.0,
+        )
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        [
+            quantize_u8(srgb_oetf(raw.x)),
+            quantize_u8(srgb_oetf(raw.y)),
+            quantize_u8(srgb_oetf(raw.z)),
+        ]
+    }
+}
+
+/// Gamma-encoded sRGB with alpha, stored as 4 `u8` channels.
+pub struct EncodedSrgbU8Alpha;
+
+impl ColorEncoding for EncodedSrgbU8Alpha {
+    type Repr = [u8; 4];
+    type ComponentStruct = Srgb8AlphaComponents;
+    type LinearSpace = super::oklab::LinearSrgb;
+
+    const NAME: &'static str = "EncodedSrgbU8Alpha";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (
+            Vec3::new(
+                srgb_eotf(repr[0] as f32 / 255.0),
+                srgb_eotf(repr[1] as f32 / 255.0),
+                srgb_eotf(repr[2] as f32 / 255.0),
+            ),
+            repr[3] as f32 / 255.0,
+        )
+    }
+
+    fn dst_transform_raw(raw: Vec3, alpha: f32) -> Self::Repr {
+        [
+            quantize_u8(srgb_oetf(raw.x)),
+            quantize_u8(srgb_oetf(raw.y)),
+            quantize_u8(srgb_oetf(raw.z)),
+            quantize_u8(alpha),
+        ]
+    }
+}
+
+/// Gamma-encoded sRGB, stored as 3 `u16` channels with no alpha, for formats that need more than
+/// 8 bits of precision per channel.
+pub struct EncodedSrgbU16;
+
+impl ColorEncoding for EncodedSrgbU16 {
+    type Repr = [u16; 3];
+    type ComponentStruct = Srgb16Components;
+    type LinearSpace = super::oklab::LinearSrgb;
+
+    const NAME: &'static str = "EncodedSrgbU16";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (
+            Vec3::new(
+                srgb_eotf(repr[0] as f32 / 65535.0),
+                srgb_eotf(repr[1] as f32 / 65535.0),
+                srgb_eotf(repr[2] as f32 / 65535.0),
+            ),
+            1.0,
+        )
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        [
+            quantize_u16(srgb_oetf(raw.x)),
+            quantize_u16(srgb_oetf(raw.y)),
+            quantize_u16(srgb_oetf(raw.z)),
+        ]
+    }
+}
+
+pub(crate) fn quantize_u8(normalized: f32) -> u8 {
+    (normalized.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn quantize_u16(normalized: f32) -> u16 {
+    (normalized.clamp(0.0, 1.0) * 65535.0).round() as u16
+}
+
+/// The sRGB electro-optical transfer function: gamma-encoded `[0, 1]` to linear `[0, 1]`.
+fn srgb_eotf(encoded: f32) -> f32 {
+    if encoded <= 0.04045 {
+        encoded / 12.92
+    } else {
+        ((encoded + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// The sRGB opto-electronic transfer function: linear `[0, 1]` to gamma-encoded `[0, 1]`.
+fn srgb_oetf(linear: f32) -> f32 {
+    if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u8_quantizer_round_trips_srgb() {
+        let repr: [u8; 3] = [0, 64, 255];
+        let (linear, alpha) = EncodedSrgbU8::src_transform_raw(repr);
+        assert_eq!(alpha, 1.0);
+        assert_eq!(EncodedSrgbU8::dst_transform_raw(linear, alpha), repr);
+    }
+
+    #[test]
+    fn u8_alpha_quantizer_round_trips_srgb() {
+        let repr: [u8; 4] = [0, 64, 255, 128];
+        let (linear, alpha) = EncodedSrgbU8Alpha::src_transform_raw(repr);
+        assert_eq!(EncodedSrgbU8Alpha::dst_transform_raw(linear, alpha), repr);
+    }
+
+    #[test]
+    fn u16_quantizer_round_trips_srgb() {
+        let repr: [u16; 3] = [0, 16384, 65535];
+        let (linear, alpha) = EncodedSrgbU16::src_transform_raw(repr);
+        assert_eq!(alpha, 1.0);
+        assert_eq!(EncodedSrgbU16::dst_transform_raw(linear, alpha), repr);
+    }
+}
@@ -0,0 +1,129 @@
+//! The HSL (hue, saturation, lightness) cylindrical color encoding.
+
+use glam::Vec3;
+
+use crate::details::traits::{AdjustSaturation, ColorEncoding, ComponentStructFor, HueRotate};
+use crate::encodings::hsv::{hue_from_rgb, lerp_hue_degrees, wrap_degrees};
+use crate::encodings::oklab::LinearSrgb;
+use crate::Color;
+
+/// The `{ h, s, l }` component struct backing [`Hsl`]. `h` is in degrees, `[0, 360)`; `s` and `l`
+/// are in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct HslComponents {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+unsafe impl ComponentStructFor<Vec3> for HslComponents {
+    fn cast(repr: &Vec3) -> &Self {
+        unsafe { &*(repr as *const Vec3 as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut Vec3) -> &mut Self {
+        unsafe { &mut *(repr as *mut Vec3 as *mut Self) }
+    }
+}
+
+/// The HSL (hue, saturation, lightness) cylindrical encoding, over the sRGB primaries and D65
+/// white point.
+///
+/// Hue is angular and raw math on `{ h, s, l }` is meaningless, so `Hsl` does not implement
+/// [`WorkingEncoding`][crate::details::traits::WorkingEncoding] — doing so would pull it through
+/// the generic [`LinearInterpolate`][crate::details::traits::LinearInterpolate] blanket impl,
+/// which would interpolate `h` linearly instead of along the shortest arc. Use [`Color::lerp`]
+/// instead, which takes the shortest path around the hue circle.
+pub struct Hsl;
+
+impl ColorEncoding for Hsl {
+    type Repr = Vec3;
+    type ComponentStruct = HslComponents;
+    type LinearSpace = LinearSrgb;
+
+    const NAME: &'static str = "Hsl";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (hsl_to_linear_srgb(repr), 1.0)
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        linear_srgb_to_hsl(raw)
+    }
+}
+
+impl HueRotate for Hsl {
+    fn hue_rotate(repr: Self::Repr, degrees: f32) -> Self::Repr {
+        Vec3::new(wrap_degrees(repr.x + degrees), repr.y, repr.z)
+    }
+}
+
+impl AdjustSaturation for Hsl {
+    fn adjust_saturation(repr: Self::Repr, factor: f32) -> Self::Repr {
+        Vec3::new(repr.x, (repr.y * factor).clamp(0.0, 1.0), repr.z)
+    }
+}
+
+impl Color<Hsl> {
+    /// Rotates this color's hue by `degrees`, wrapping around the hue circle.
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Color {
+            repr: Hsl::hue_rotate(self.repr, degrees),
+        }
+    }
+
+    /// Scales this color's saturation by `factor`, clamped to `[0, 1]`.
+    pub fn with_saturation(self, factor: f32) -> Self {
+        Color {
+            repr: Hsl::adjust_saturation(self.repr, factor),
+        }
+    }
+
+    /// Interpolates between `self` and `to` by `factor`, taking the shortest path around the hue
+    /// circle rather than interpolating `h` linearly.
+    pub fn lerp(self, to: Self, factor: f32) -> Self {
+        let h = lerp_hue_degrees(self.repr.x, to.repr.x, factor);
+        let s = self.repr.y + (to.repr.y - self.repr.y) * factor;
+        let l = self.repr.z + (to.repr.z - self.repr.z) * factor;
+        Color {
+            repr: Vec3::new(h, s, l),
+        }
+    }
+}
+
+fn hsl_to_linear_srgb(hsl: Vec3) -> Vec3 {
+    let (h, s, l) = (hsl.x, hsl.y, hsl.z);
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h_prime = wrap_degrees(h) / 60.0;
+    let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+
+    let (r, g, b) = match h_prime as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Vec3::new(r + m, g + m, b + m)
+}
+
+fn linear_srgb_to_hsl(rgb: Vec3) -> Vec3 {
+    let max = rgb.x.max(rgb.y).max(rgb.z);
+    let min = rgb.x.min(rgb.y).min(rgb.z);
+    let delta = max - min;
+
+    let l = (max + min) * 0.5;
+    let s = if delta <= 0.0 {
+        0.0
+    } else {
+        delta / (1.0 - (2.0 * l - 1.0).abs())
+    };
+
+    let h = hue_from_rgb(rgb, max, delta);
+
+    Vec3::new(h, s, l)
+}
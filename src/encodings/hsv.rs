@@ -0,0 +1,183 @@
+//! The HSV (hue, saturation, value) cylindrical color encoding.
+
+use glam::Vec3;
+
+use crate::details::traits::{AdjustSaturation, ColorEncoding, ComponentStructFor, HueRotate};
+use crate::encodings::oklab::LinearSrgb;
+use crate::Color;
+
+/// The `{ h, s, v }` component struct backing [`Hsv`]. `h` is in degrees, `[0, 360)`; `s` and `v`
+/// are in `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct HsvComponents {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+unsafe impl ComponentStructFor<Vec3> for HsvComponents {
+    fn cast(repr: &Vec3) -> &Self {
+        unsafe { &*(repr as *const Vec3 as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut Vec3) -> &mut Self {
+        unsafe { &mut *(repr as *mut Vec3 as *mut Self) }
+    }
+}
+
+/// The HSV (hue, saturation, value) cylindrical encoding, over the sRGB primaries and D65 white
+/// point.
+///
+/// Hue is angular and raw math on `{ h, s, v }` is meaningless, so `Hsv` does not implement
+/// [`WorkingEncoding`][crate::details::traits::WorkingEncoding] — doing so would pull it through
+/// the generic [`LinearInterpolate`][crate::details::traits::LinearInterpolate] blanket impl,
+/// which would interpolate `h` linearly instead of along the shortest arc. Use [`Color::lerp`]
+/// instead, which takes the shortest path around the hue circle.
+pub struct Hsv;
+
+impl ColorEncoding for Hsv {
+    type Repr = Vec3;
+    type ComponentStruct = HsvComponents;
+    type LinearSpace = LinearSrgb;
+
+    const NAME: &'static str = "Hsv";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (hsv_to_linear_srgb(repr), 1.0)
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        linear_srgb_to_hsv(raw)
+    }
+}
+
+impl HueRotate for Hsv {
+    fn hue_rotate(repr: Self::Repr, degrees: f32) -> Self::Repr {
+        Vec3::new(wrap_degrees(repr.x + degrees), repr.y, repr.z)
+    }
+}
+
+impl AdjustSaturation for Hsv {
+    fn adjust_saturation(repr: Self::Repr, factor: f32) -> Self::Repr {
+        Vec3::new(repr.x, (repr.y * factor).clamp(0.0, 1.0), repr.z)
+    }
+}
+
+impl Color<Hsv> {
+    /// Rotates this color's hue by `degrees`, wrapping around the hue circle.
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        Color {
+            repr: Hsv::hue_rotate(self.repr, degrees),
+        }
+    }
+
+    /// Scales this color's saturation by `factor`, clamped to `[0, 1]`.
+    pub fn with_saturation(self, factor: f32) -> Self {
+        Color {
+            repr: Hsv::adjust_saturation(self.repr, factor),
+        }
+    }
+
+    /// Interpolates between `self` and `to` by `factor`, taking the shortest path around the hue
+    /// circle rather than interpolating `h` linearly.
+    pub fn lerp(self, to: Self, factor: f32) -> Self {
+        let h = lerp_hue_degrees(self.repr.x, to.repr.x, factor);
+        let s = self.repr.y + (to.repr.y - self.repr.y) * factor;
+        let v = self.repr.z + (to.repr.z - self.repr.z) * factor;
+        Color {
+            repr: Vec3::new(h, s, v),
+        }
+    }
+}
+
+pub(crate) fn wrap_degrees(degrees: f32) -> f32 {
+    degrees.rem_euclid(360.0)
+}
+
+pub(crate) fn lerp_hue_degrees(from: f32, to: f32, factor: f32) -> f32 {
+    let mut delta = (to - from) % 360.0;
+    if delta > 180.0 {
+        delta -= 360.0;
+    } else if delta < -180.0 {
+        delta += 360.0;
+    }
+    wrap_degrees(from + delta * factor)
+}
+
+fn hsv_to_linear_srgb(hsv: Vec3) -> Vec3 {
+    let (h, s, v) = (hsv.x, hsv.y, hsv.z);
+    let c = v * s;
+    let h_prime = wrap_degrees(h) / 60.0;
+    let x = c * (1.0 - (h_prime % 2.0 - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = match h_prime as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Vec3::new(r + m, g + m, b + m)
+}
+
+fn linear_srgb_to_hsv(rgb: Vec3) -> Vec3 {
+    let max = rgb.x.max(rgb.y).max(rgb.z);
+    let min = rgb.x.min(rgb.y).min(rgb.z);
+    let delta = max - min;
+
+    let v = max;
+    let s = if max <= 0.0 { 0.0 } else { delta / max };
+
+    let h = hue_from_rgb(rgb, max, delta);
+
+    Vec3::new(h, s, v)
+}
+
+/// Shared hue computation between HSV and HSL: both use the standard hexcone hue formula, only
+/// differing in how saturation and value/lightness are derived from `max`/`min`.
+pub(crate) fn hue_from_rgb(rgb: Vec3, max: f32, delta: f32) -> f32 {
+    if delta <= 0.0 {
+        return 0.0;
+    }
+
+    let h = if max == rgb.x {
+        ((rgb.y - rgb.z) / delta) % 6.0
+    } else if max == rgb.y {
+        (rgb.z - rgb.x) / delta + 2.0
+    } else {
+        (rgb.x - rgb.y) / delta + 4.0
+    };
+
+    wrap_degrees(h * 60.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_degrees_normalizes_into_0_360() {
+        assert_eq!(wrap_degrees(370.0), 10.0);
+        assert_eq!(wrap_degrees(-10.0), 350.0);
+        assert_eq!(wrap_degrees(360.0), 0.0);
+    }
+
+    #[test]
+    fn lerp_hue_degrees_takes_the_shortest_arc() {
+        // 350 -> 10 is a 20 degree arc through 0, not the 340 degree arc the long way around.
+        assert!((lerp_hue_degrees(350.0, 10.0, 0.5) - 0.0).abs() < 1e-4);
+        assert!((lerp_hue_degrees(10.0, 350.0, 0.5) - 0.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn hsv_round_trips_through_linear_srgb() {
+        let original = Vec3::new(210.0, 0.6, 0.8);
+        let linear = hsv_to_linear_srgb(original);
+        let back = linear_srgb_to_hsv(linear);
+        assert!((back - original).abs().max_element() < 1e-4);
+    }
+}
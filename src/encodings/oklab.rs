@@ -0,0 +1,226 @@
+//! The Oklab and Oklch perceptual color encodings.
+
+use glam::{Mat3, Vec3};
+use kolor::details::color::{RGBPrimaries, WhitePoint};
+
+use crate::details::traits::{
+    ColorEncoding, ComponentStructFor, LinearColorSpace, PerceptualEncoding, WorkingEncoding,
+};
+
+/// The linear color space that [`Oklab`] and [`Oklch`] are derived from: linear sRGB primaries
+/// (BT.709) with a D65 white point.
+pub struct LinearSrgb;
+
+impl LinearColorSpace for LinearSrgb {
+    const PRIMARIES: RGBPrimaries = RGBPrimaries::BT_709;
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    // The standard BT.709/sRGB primaries matrix, relative to a D65 reference white.
+    #[rustfmt::skip]
+    const RGB_TO_XYZ: Mat3 = Mat3::from_cols(
+        // column-major: each Vec3 here is a *column* of the matrix
+        Vec3::new(0.4124564, 0.2126729, 0.0193339),
+        Vec3::new(0.3575761, 0.7151522, 0.1191920),
+        Vec3::new(0.1804375, 0.0721750, 0.9503041),
+    );
+}
+
+/// The `{ L, a, b }` component struct backing [`Oklab`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct LabComponents {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+unsafe impl ComponentStructFor<Vec3> for LabComponents {
+    fn cast(repr: &Vec3) -> &Self {
+        unsafe { &*(repr as *const Vec3 as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut Vec3) -> &mut Self {
+        unsafe { &mut *(repr as *mut Vec3 as *mut Self) }
+    }
+}
+
+/// The `{ L, C, h }` component struct backing [`Oklch`], where `h` is in radians.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct LchComponents {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+unsafe impl ComponentStructFor<Vec3> for LchComponents {
+    fn cast(repr: &Vec3) -> &Self {
+        unsafe { &*(repr as *const Vec3 as *const Self) }
+    }
+
+    fn cast_mut(repr: &mut Vec3) -> &mut Self {
+        unsafe { &mut *(repr as *mut Vec3 as *mut Self) }
+    }
+}
+
+/// The [Oklab](https://bottosson.github.io/posts/oklab/) perceptual color encoding.
+///
+/// Oklab is built to be perceptually uniform: equal Euclidean distances between colors are
+/// intended to correspond to roughly equal perceived differences, which makes it a good space
+/// to blend or otherwise do math in. Its `LinearSpace` is linear sRGB (BT.709 primaries, D65
+/// white point).
+pub struct Oklab;
+
+impl ColorEncoding for Oklab {
+    type Repr = Vec3;
+    type ComponentStruct = LabComponents;
+    type LinearSpace = LinearSrgb;
+
+    const NAME: &'static str = "Oklab";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        (oklab_to_linear_srgb(repr), 1.0)
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        linear_srgb_to_oklab(raw)
+    }
+}
+
+impl WorkingEncoding for Oklab {}
+impl PerceptualEncoding for Oklab {}
+
+/// The [Oklab](https://bottosson.github.io/posts/oklab/) encoding in cylindrical (polar) form:
+/// lightness `L`, chroma `C`, and hue `h` (in radians).
+///
+/// Because hue is an angle, [`Oklch`] does not implement [`WorkingEncoding`] — doing so would
+/// pull it through the generic [`LinearInterpolate`] blanket impl, which interpolates `h`
+/// linearly rather than along the shortest arc. [`Color::lerp`][crate::Color::lerp] implements
+/// hue-aware interpolation directly instead.
+///
+/// [`WorkingEncoding`]: crate::details::traits::WorkingEncoding
+/// [`LinearInterpolate`]: crate::details::traits::LinearInterpolate
+pub struct Oklch;
+
+impl ColorEncoding for Oklch {
+    type Repr = Vec3;
+    type ComponentStruct = LchComponents;
+    type LinearSpace = LinearSrgb;
+
+    const NAME: &'static str = "Oklch";
+
+    fn src_transform_raw(repr: Self::Repr) -> (Vec3, f32) {
+        let lab = lch_to_lab(repr);
+        (oklab_to_linear_srgb(lab), 1.0)
+    }
+
+    fn dst_transform_raw(raw: Vec3, _alpha: f32) -> Self::Repr {
+        let lab = linear_srgb_to_oklab(raw);
+        lab_to_lch(lab)
+    }
+}
+
+impl crate::Color<Oklch> {
+    /// Interpolates between `self` and `to` by `factor`, treating hue as an angle and taking the
+    /// shortest path around the hue circle rather than interpolating it linearly.
+    pub fn lerp(self, to: Self, factor: f32) -> Self {
+        let from = self.repr;
+        let to = to.repr;
+
+        let l = from.x + (to.x - from.x) * factor;
+        let c = from.y + (to.y - from.y) * factor;
+
+        let mut delta = to.z - from.z;
+        if delta > core::f32::consts::PI {
+            delta -= core::f32::consts::TAU;
+        } else if delta < -core::f32::consts::PI {
+            delta += core::f32::consts::TAU;
+        }
+        let mut h = from.z + delta * factor;
+        if h < 0.0 {
+            h += core::f32::consts::TAU;
+        } else if h >= core::f32::consts::TAU {
+            h -= core::f32::consts::TAU;
+        }
+
+        crate::Color {
+            repr: Vec3::new(l, c, h),
+        }
+    }
+}
+
+pub(crate) fn linear_srgb_to_oklab(rgb: Vec3) -> Vec3 {
+    let l = 0.4122214708 * rgb.x + 0.5363325363 * rgb.y + 0.0514459929 * rgb.z;
+    let m = 0.2119034982 * rgb.x + 0.6806995451 * rgb.y + 0.1073969566 * rgb.z;
+    let s = 0.0883024619 * rgb.x + 0.2817188376 * rgb.y + 0.6299787005 * rgb.z;
+
+    let l_ = l.cbrt();
+    let m_ = m.cbrt();
+    let s_ = s.cbrt();
+
+    Vec3::new(
+        0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
+        1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
+        0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
+    )
+}
+
+pub(crate) fn oklab_to_linear_srgb(lab: Vec3) -> Vec3 {
+    let l_ = lab.x + 0.3963377774 * lab.y + 0.2158037573 * lab.z;
+    let m_ = lab.x - 0.1055613458 * lab.y - 0.0638541728 * lab.z;
+    let s_ = lab.x - 0.0894841775 * lab.y - 1.2914855480 * lab.z;
+
+    let l = l_ * l_ * l_;
+    let m = m_ * m_ * m_;
+    let s = s_ * s_ * s_;
+
+    Vec3::new(
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    )
+}
+
+/// Converts Oklab `{ L, a, b }` to Oklch `{ L, C, h }`, guarding against an unstable hue when
+/// chroma is ~0.
+pub(crate) fn lab_to_lch(lab: Vec3) -> Vec3 {
+    let c = (lab.y * lab.y + lab.z * lab.z).sqrt();
+    let h = if c < 1e-5 { 0.0 } else { lab.z.atan2(lab.y) };
+    Vec3::new(lab.x, c, h)
+}
+
+pub(crate) fn lch_to_lab(lch: Vec3) -> Vec3 {
+    Vec3::new(lch.x, lch.y * lch.z.cos(), lch.y * lch.z.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: Vec3, b: Vec3, eps: f32) -> bool {
+        (a - b).abs().max_element() <= eps
+    }
+
+    #[test]
+    fn white_maps_to_achromatic_oklab() {
+        // A known value: linear sRGB white has no chroma in Oklab, and L is ~1.
+        let oklab = linear_srgb_to_oklab(Vec3::ONE);
+        assert!(approx_eq(oklab, Vec3::new(1.0, 0.0, 0.0), 1e-4));
+    }
+
+    #[test]
+    fn oklab_round_trips_through_linear_srgb() {
+        let original = Vec3::new(0.2, 0.5, 0.8);
+        let oklab = linear_srgb_to_oklab(original);
+        let back = oklab_to_linear_srgb(oklab);
+        assert!(approx_eq(original, back, 1e-4));
+    }
+
+    #[test]
+    fn oklch_round_trips_through_oklab() {
+        let lab = linear_srgb_to_oklab(Vec3::new(0.1, 0.6, 0.3));
+        let lch = lab_to_lch(lab);
+        let back = lch_to_lab(lch);
+        assert!(approx_eq(lab, back, 1e-4));
+    }
+}
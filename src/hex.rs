@@ -0,0 +1,158 @@
+//! Packed-integer and hex-string construction for [`Color<EncodedSrgbU8Alpha>`].
+//!
+//! Asset pipelines and framebuffers often hand colors over as a single packed word or a hex
+//! literal rather than separate components. These constructors take that representation directly,
+//! without the caller having to unpack it into bytes by hand first.
+
+use core::fmt;
+
+use crate::encodings::encoded_srgb_u8::EncodedSrgbU8Alpha;
+use crate::Color;
+
+/// The order in which channels appear in a packed `u32`. Hex strings are always read in
+/// `"#RRGGBBAA"` order by `from_hex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrder {
+    /// `0xRRGGBBAA`.
+    Rgba,
+    /// `0xAARRGGBB`.
+    Argb,
+}
+
+/// An error returned when parsing a hex color string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The string didn't start with `#`.
+    MissingHashPrefix,
+    /// The string wasn't 6 or 8 hex digits long (after the `#`).
+    InvalidLength { found: usize },
+    /// One of the characters wasn't a valid hex digit.
+    InvalidDigit,
+}
+
+impl fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromHexError::MissingHashPrefix => write!(f, "hex color string must start with '#'"),
+            FromHexError::InvalidLength { found } => write!(
+                f,
+                "hex color string must have 6 or 8 hex digits, found {}",
+                found
+            ),
+            FromHexError::InvalidDigit => write!(f, "hex color string contains a non-hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for FromHexError {}
+
+impl Color<EncodedSrgbU8Alpha> {
+    /// Builds a color from a packed `0xRRGGBBAA` `u32`.
+    pub fn from_u32_rgba(packed: u32) -> Self {
+        Self::from_u32(packed, ChannelOrder::Rgba)
+    }
+
+    /// Builds a color from a packed `u32`, in the given channel order.
+    pub fn from_u32(packed: u32, order: ChannelOrder) -> Self {
+        let bytes = packed.to_be_bytes();
+        let repr = match order {
+            ChannelOrder::Rgba => bytes,
+            ChannelOrder::Argb => [bytes[1], bytes[2], bytes[3], bytes[0]],
+        };
+        Color { repr }
+    }
+
+    /// Packs this color into a `0xRRGGBBAA` `u32`.
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.repr)
+    }
+
+    /// Parses a hex color string in `"#RRGGBB"` or `"#RRGGBBAA"` form (case-insensitive). When no
+    /// alpha digits are present, alpha is set fully opaque.
+    pub fn from_hex(hex: &str) -> Result<Self, FromHexError> {
+        let digits = hex.strip_prefix('#').ok_or(FromHexError::MissingHashPrefix)?;
+
+        // `digits.len()` and the byte-index slicing below both assume single-byte characters; a
+        // non-hex-digit multibyte character could otherwise slice through the middle of a
+        // codepoint and panic instead of hitting the `InvalidDigit` error path.
+        if !digits.is_ascii() {
+            return Err(FromHexError::InvalidDigit);
+        }
+
+        let channel = |i: usize| -> Result<u8, FromHexError> {
+            let pair = &digits[i..i + 2];
+            // `from_str_radix` would accept a leading `+`, which is not a hex digit.
+            if !pair.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(FromHexError::InvalidDigit);
+            }
+            u8::from_str_radix(pair, 16).map_err(|_| FromHexError::InvalidDigit)
+        };
+
+        match digits.len() {
+            6 => Ok(Color {
+                repr: [channel(0)?, channel(2)?, channel(4)?, 255],
+            }),
+            8 => Ok(Color {
+                repr: [channel(0)?, channel(2)?, channel(4)?, channel(6)?],
+            }),
+            found => Err(FromHexError::InvalidLength { found }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn u32_round_trips_through_color() {
+        let packed = 0x11223344;
+        let color = Color::<EncodedSrgbU8Alpha>::from_u32_rgba(packed);
+        assert_eq!(color.to_u32(), packed);
+    }
+
+    #[test]
+    fn from_u32_respects_channel_order() {
+        let rgba = Color::<EncodedSrgbU8Alpha>::from_u32(0x11223344, ChannelOrder::Rgba);
+        assert_eq!(rgba.repr, [0x11, 0x22, 0x33, 0x44]);
+
+        let argb = Color::<EncodedSrgbU8Alpha>::from_u32(0x11223344, ChannelOrder::Argb);
+        assert_eq!(argb.repr, [0x22, 0x33, 0x44, 0x11]);
+    }
+
+    #[test]
+    fn from_hex_parses_rgb_and_rgba() {
+        let rgb = Color::<EncodedSrgbU8Alpha>::from_hex("#112233").unwrap();
+        assert_eq!(rgb.repr, [0x11, 0x22, 0x33, 255]);
+
+        let rgba = Color::<EncodedSrgbU8Alpha>::from_hex("#11223344").unwrap();
+        assert_eq!(rgba.repr, [0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn from_hex_rejects_bad_input() {
+        assert_eq!(
+            Color::<EncodedSrgbU8Alpha>::from_hex("112233"),
+            Err(FromHexError::MissingHashPrefix)
+        );
+        assert_eq!(
+            Color::<EncodedSrgbU8Alpha>::from_hex("#1122"),
+            Err(FromHexError::InvalidLength { found: 4 })
+        );
+        assert_eq!(
+            Color::<EncodedSrgbU8Alpha>::from_hex("#zzzzzz"),
+            Err(FromHexError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn from_hex_rejects_non_ascii_without_panicking() {
+        // '€' is 3 bytes in UTF-8, so "#€€" has a 6-byte digit portion but only 2 characters;
+        // byte-indexed slicing on that would previously panic on a non-char-boundary instead of
+        // returning `InvalidDigit`.
+        assert_eq!(
+            Color::<EncodedSrgbU8Alpha>::from_hex("#€€"),
+            Err(FromHexError::InvalidDigit)
+        );
+    }
+}
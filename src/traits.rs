@@ -1,6 +1,6 @@
 use crate::Color;
 
-use glam::Vec3;
+use glam::{Mat3, Vec3};
 use kolor::details::color::{RGBPrimaries, WhitePoint};
 
 pub trait ColorEncoding: Sized + 'static {
@@ -66,6 +66,10 @@ pub trait WorkingEncoding: ColorEncoding {}
 pub trait LinearColorSpace {
     const PRIMARIES: RGBPrimaries;
     const WHITE_POINT: WhitePoint;
+
+    /// The 3x3 matrix that converts linear RGB values in this space into CIE XYZ tristimulus
+    /// values, under this space's own [`WHITE_POINT`][Self::WHITE_POINT].
+    const RGB_TO_XYZ: Mat3;
 }
 
 /// A trait that marks `Self` as being a color encoding which is able to be directly converted from `SrcEnc`,
@@ -79,12 +83,21 @@ where
     /// If required or desired, perform a mapping of some kind to the input
     /// before it undergoes its source transform. This may be desirable to perform some form of
     /// gamut mapping if the src encoding has a larger size of representable colors than te dst encoding.
+    ///
+    /// [`GamutClip`][crate::details::gamut::GamutClip] and
+    /// [`GamutCompress`][crate::details::gamut::GamutCompress] are ready-made strategies for this.
     #[inline(always)]
     fn map_src(_src: &mut SrcEnc::Repr) { }
 }
 
 /// Performs the raw conversion from the [`LinearColorSpace`] represented by `SrcSpc` to
 /// the [`LinearColorSpace`] represented by `Self`.
+///
+/// The generated implementation composes the primaries matrices of both spaces with a Bradford
+/// chromatic-adaptation step between their [`WHITE_POINT`][LinearColorSpace::WHITE_POINT]s, so
+/// conversions between spaces with different reference whites (e.g. D65 sRGB to a D50 working
+/// space) map correctly. When both white points match, the adaptation step is the identity and
+/// behavior is unchanged.
 pub trait LinearConvertFromRaw<SrcSpace: LinearColorSpace>: LinearColorSpace {
     fn linear_part_raw(raw: &mut Vec3);
 }